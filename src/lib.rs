@@ -134,10 +134,446 @@
 //! assert_ne!(get_hash(&v1), get_hash(&v2));
 //!
 //! ```
+//!
+//! ## Generic Structs and Custom Bounds Example
+//!
+//! [`HashWith`] forwards a struct's generics into the generated `impl`, so generic
+//! structs work out of the box. By default every type parameter gets a `T: Hash` bound,
+//! just like the standard `#[derive(Hash)]`. When that default is wrong — for example
+//! because only some fields actually need `T: Hash` — use the container-level
+//! `#[hash_with(bound = "...")]` attribute to supply the `where` predicates yourself, or
+//! `#[hash_with(bound = "")]` to emit no bound at all.
+//!
+//! ```rust
+//! # use hash_with::HashWith;
+//! #
+//! /// Only `name` is hashed, so `T` itself doesn't need `Hash`.
+//! #[derive(HashWith)]
+//! #[hash_with(bound = "")]
+//! struct Wrapper<T> {
+//!     name: String,
+//!     #[hash_without]
+//!     inner: T,
+//! }
+//! ```
+//!
+//! ## Enum and Tuple Struct Example
+//!
+//! [`HashWith`] also supports tuple structs and enums. Every `#[hash_with(...)]`,
+//! `#[hash_with = "fn"]` and `#[hash_without]` attribute works on enum variant fields
+//! exactly as it does on named struct fields, using `self.0`, `self.1`, etc. to refer to
+//! tuple fields. Each variant's discriminant is hashed first, so two variants that hold
+//! structurally identical payloads never collide.
+//!
+//! ```rust
+//! # use hash_with::HashWith;
+//! #
+//! #[derive(HashWith)]
+//! enum Setting {
+//!     Flag(bool),
+//!     Brightness(#[hash_with(self.0.to_bits())] f64),
+//!     Named { label: String },
+//! }
+//! ```
+//!
+//! ## Domain-Separated Hashing Example
+//!
+//! Plain concatenation-style hashing can produce collisions between structurally
+//! different values when several variable-length fields are written without a delimiter,
+//! as `#[hash_with(bytes)]` fields are: e.g. `{a: b"foo", b: b"bar"}` and
+//! `{a: b"foob", b: b"ar"}` feed the same bytes to the hasher. (`String` fields hashed
+//! through `Hash::hash` do not collide this way, since `str`'s `Hash` impl already ends
+//! each string with a terminator byte.) The container-level
+//! `#[hash_with(domain_separated)]` attribute writes a type-name domain tag once and a
+//! position discriminator before every field, so field boundaries become part of the
+//! hashed input, following the format-ambiguity protection used by the Aptos/Diem crypto
+//! hashing module. Only the position is written, not each field's length. This is opt-in,
+//! so existing users' hashes don't change.
+//!
+//! ```rust
+//! # use hash_with::HashWith;
+//! #
+//! #[derive(HashWith)]
+//! #[hash_with(domain_separated)]
+//! struct Pair {
+//!     #[hash_with(bytes)]
+//!     a: Vec<u8>,
+//!     #[hash_with(bytes)]
+//!     b: Vec<u8>,
+//! }
+//! ```
+//!
+//! ## Deriving a Matching `PartialEq`/`Eq` Example
+//!
+//! `#[hash_with]`'s `#[hash_without]` attribute silently breaks the
+//! `k1 == k2 ⇒ hash(k1) == hash(k2)` contract if you also `#[derive(PartialEq)]`,
+//! since the standard derive would compare the ignored field while [`HashWith`] ignores
+//! it. The container-level `#[hash_with(eq)]` attribute derives `PartialEq`/`Eq` from the
+//! same field set the hash uses instead: fields marked `#[hash_without]` are skipped,
+//! and fields with a `#[hash_with(expr)]`/`#[hash_with = "fn"]` projection are compared
+//! through that same projection rather than the raw field.
+//!
+//! ```rust
+//! # use hash_with::HashWith;
+//! #
+//! #[derive(HashWith, Debug)]
+//! #[hash_with(eq)]
+//! struct CacheKey {
+//!     id: u32,
+//!     #[hash_without]
+//!     last_accessed: f64,
+//! }
+//!
+//! let a = CacheKey { id: 1, last_accessed: 0.0 };
+//! let b = CacheKey { id: 1, last_accessed: 99.9 };
+//! assert_eq!(a, b);
+//! ```
+//!
+//! ## Hashing Raw Bytes Example
+//!
+//! The `#[hash_with(bytes = "expr")]` attribute (and the bare `#[hash_with(bytes)]` form
+//! for fields that are themselves `AsRef<[u8]>`) pushes bytes straight into the hasher
+//! via [`Hasher::write`](std::hash::Hasher::write) instead of going through [`Hash::hash`].
+//! This is useful for digests, serialized buffers, or `to_bits()` output, where the
+//! per-element dispatch of hashing a slice isn't needed.
+//!
+//! ```rust
+//! # use hash_with::HashWith;
+//! #
+//! #[derive(HashWith)]
+//! struct Digest {
+//!     #[hash_with(bytes)]
+//!     checksum: Vec<u8>,
+//!     #[hash_with(bytes = "self.value.to_le_bytes()")]
+//!     value: u32,
+//! }
+//! ```
 
 use proc_macro::TokenStream;
-use quote::{quote, ToTokens};
-use syn::{parse_macro_input, parse_str, Data, DeriveInput, Expr, Fields, Lit, Meta, MetaList, MetaNameValue};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::visit_mut::VisitMut;
+use syn::{
+    parse_macro_input, parse_quote, parse_str, Attribute, Data, DeriveInput, Expr, Fields, Ident,
+    GenericParam, Index, Lit, Member, Meta, MetaList, MetaNameValue, WherePredicate,
+};
+
+/// A field's `#[hash_with(...)]` / `#[hash_with = "fn"]` / `#[hash_without]` attribute,
+/// parsed once and shared between the generated `Hash` and (optional) `PartialEq` impls
+/// so both always agree on how a field is projected.
+enum FieldMode {
+    /// No attribute: hash/compare the field's value directly.
+    Default,
+    /// `#[hash_without]`: excluded from hashing, and from equality when `#[hash_with(eq)]` is set.
+    Skip,
+    /// `#[hash_with = "fn"]`: `fn` is called with `(&field, state)` to hash the field.
+    Func(syn::Path),
+    /// `#[hash_with(expr)]`: `expr` is hashed in place of the raw field.
+    ///
+    /// Stored as the raw token text rather than a parsed [`Expr`] because it's written
+    /// in terms of `self.#member`, which must be re-parsed and substituted with whatever
+    /// expression actually reads the field at each call site (see [`project_expr`]).
+    Expr(String),
+    /// `#[hash_with(bytes = "expr")]` or bare `#[hash_with(bytes)]`: pushes the raw bytes
+    /// of `expr` (or the field itself, for the bare form) straight into the hasher via
+    /// `Hasher::write`, instead of going through `Hash::hash`. `Some(expr)` holds the raw
+    /// token text of `expr` (substituted the same way as [`FieldMode::Expr`]); `None`
+    /// means the field itself is the `AsRef<[u8]>` value to write.
+    Bytes(Option<String>),
+}
+
+/// Parses the `#[hash_with(...)]` / `#[hash_with = "fn"]` / `#[hash_without]` attributes
+/// on a single field into a [`FieldMode`].
+fn parse_field_mode(attrs: &[Attribute]) -> FieldMode {
+
+    let mut mode = FieldMode::Default;
+
+    for attr in attrs {
+        if attr.path().is_ident("hash_with") {
+
+            mode = match &attr.meta {
+                Meta::NameValue(
+                    MetaNameValue {
+                        value: Expr::Lit(syn::ExprLit {
+                            lit: Lit::Str(
+                                 function_name_str_with_quotes
+                            ),
+                            ..
+                        }),
+                    .. }
+                ) => {
+                    let func = function_name_str_with_quotes.parse_with(syn::Path::parse_mod_style).expect("Failed to parse string!");
+                    FieldMode::Func(func)
+                },
+                // Handles the list implementation (i.e. `#[hash_with( ... )]`), including
+                // the `bytes = "expr"` and bare `bytes` forms of `FieldMode::Bytes`.
+                Meta::List(
+                    MetaList {
+                        tokens,
+                    .. }
+                ) => {
+                    if let Ok(MetaNameValue { path, value: Expr::Lit(syn::ExprLit { lit: Lit::Str(bytes_expr), .. }), .. }) = syn::parse2::<MetaNameValue>(tokens.clone()) {
+                        if path.is_ident("bytes") {
+                            FieldMode::Bytes(Some(bytes_expr.value()))
+                        } else {
+                            FieldMode::Expr(tokens.to_string())
+                        }
+                    } else if syn::parse2::<syn::Path>(tokens.clone()).is_ok_and(|path| path.is_ident("bytes")) {
+                        FieldMode::Bytes(None)
+                    } else {
+                        FieldMode::Expr(tokens.to_string())
+                    }
+                },
+                _ => panic!("Failed to parse `{}` for `hash_with` macro.", attr.to_token_stream().to_string()),
+            };
+
+        }
+
+        if attr.path().is_ident("hash_without") {
+            mode = FieldMode::Skip;
+        }
+
+    }
+
+    mode
+
+}
+
+/// Rewrites a `#[hash_with(expr)]` expression written in terms of `self.#member` so it
+/// reads the field through `access` instead, then parses it.
+///
+/// `member` identifies the field for the purpose of the `self.#member` substitution
+/// (named fields use their identifier, tuple fields their index). `access` is the
+/// expression that actually reads the field's value at the call site: `self.#member` for
+/// struct fields (a no-op substitution), or the locally bound identifier for enum and
+/// tuple-struct fields destructured inside a `match` arm.
+///
+/// The substitution walks the parsed expression tree rather than matching on source
+/// text, so it isn't thrown off by whatever spacing `raw`'s tokens happen to carry
+/// (e.g. `self.0.to_bits()` vs. a `quote!`-produced `self . 0 . to_bits ()`).
+fn project_expr(raw: &str, member: &Member, access: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let mut expr = parse_str::<Expr>(raw).expect("Failed to parse tokens");
+    let access_expr = parse_str::<Expr>(&access.to_string()).expect("Failed to parse access expression");
+    SelfFieldReplacer { member, access: &access_expr }.visit_expr_mut(&mut expr);
+    expr.to_token_stream()
+}
+
+/// [`VisitMut`] that replaces every `self.#member` expression with `access`.
+struct SelfFieldReplacer<'a> {
+    member: &'a Member,
+    access: &'a Expr,
+}
+
+impl VisitMut for SelfFieldReplacer<'_> {
+    fn visit_expr_mut(&mut self, node: &mut Expr) {
+        let is_target = matches!(
+            node,
+            Expr::Field(field)
+                if matches!(&*field.base, Expr::Path(path) if path.path.is_ident("self"))
+                    && members_eq(&field.member, self.member)
+        );
+        if is_target {
+            *node = self.access.clone();
+            return;
+        }
+        syn::visit_mut::visit_expr_mut(self, node);
+    }
+}
+
+/// Compares two [`Member`]s by the field they identify, ignoring their spans.
+fn members_eq(a: &Member, b: &Member) -> bool {
+    match (a, b) {
+        (Member::Named(a), Member::Named(b)) => a == b,
+        (Member::Unnamed(a), Member::Unnamed(b)) => a.index == b.index,
+        _ => false,
+    }
+}
+
+/// Builds the hash statement for a single field.
+///
+/// `access` is as described on [`project_expr`]. When `access_is_ref` is `true`, `access` is already a reference (as bound by match
+/// ergonomics), so function calls don't need an extra `&`.
+fn hash_stmt_for_field(mode: &FieldMode, member: &Member, access: &proc_macro2::TokenStream, access_is_ref: bool) -> proc_macro2::TokenStream {
+    match mode {
+        FieldMode::Default => quote! { #access.hash(state); },
+        FieldMode::Skip => proc_macro2::TokenStream::new(),
+        FieldMode::Func(func) => {
+            if access_is_ref {
+                quote! { #func(#access, state); }
+            } else {
+                quote! { #func(&#access, state); }
+            }
+        },
+        FieldMode::Expr(raw) => {
+            let expr = project_expr(raw, member, access);
+            quote! { #expr.hash(state); }
+        },
+        FieldMode::Bytes(Some(raw)) => {
+            let expr = project_expr(raw, member, access);
+            quote! { state.write(#expr.as_ref()); }
+        },
+        FieldMode::Bytes(None) => quote! { state.write(#access.as_ref()); },
+    }
+}
+
+/// Builds the equality comparison for a single field when `#[hash_with(eq)]` is set, or
+/// `None` if the field (marked `#[hash_without]`) should be excluded from equality too.
+///
+/// For `#[hash_with(expr)]` fields, `expr` is evaluated against both `lhs_access` and
+/// `rhs_access` and the two results compared, so equality follows the same projection as
+/// the hash. `#[hash_with = "fn"]` has no such projection to re-evaluate directly (`fn`
+/// only ever writes into a `Hasher`), so both sides are hashed with it independently and
+/// their resulting hashes compared instead. This makes equality for `#[hash_with = "fn"]`
+/// fields only as good as `fn`'s hash: a collision makes two genuinely different values
+/// compare equal, which is a known, accepted imprecision rather than true equality.
+fn eq_expr_for_field(mode: &FieldMode, member: &Member, lhs_access: &proc_macro2::TokenStream, rhs_access: &proc_macro2::TokenStream, access_is_ref: bool) -> Option<proc_macro2::TokenStream> {
+    match mode {
+        FieldMode::Default => Some(quote! { #lhs_access == #rhs_access }),
+        FieldMode::Skip => None,
+        FieldMode::Func(func) => {
+            let (lhs_arg, rhs_arg) = if access_is_ref {
+                (quote! { #lhs_access }, quote! { #rhs_access })
+            } else {
+                (quote! { &#lhs_access }, quote! { &#rhs_access })
+            };
+            Some(quote! {
+                {
+                    let mut lhs_state = std::collections::hash_map::DefaultHasher::new();
+                    #func(#lhs_arg, &mut lhs_state);
+                    let mut rhs_state = std::collections::hash_map::DefaultHasher::new();
+                    #func(#rhs_arg, &mut rhs_state);
+                    std::hash::Hasher::finish(&lhs_state) == std::hash::Hasher::finish(&rhs_state)
+                }
+            })
+        },
+        FieldMode::Expr(raw) => {
+            let lhs_expr = project_expr(raw, member, lhs_access);
+            let rhs_expr = project_expr(raw, member, rhs_access);
+            Some(quote! { (#lhs_expr) == (#rhs_expr) })
+        },
+        // `.as_ref()` is ambiguous for types with several `AsRef` impls (e.g. `String`
+        // implements it for both `str` and `[u8]`), so the target type is pinned down
+        // with an explicit `&[u8]` binding rather than left to the `==` to infer.
+        //
+        // A bare field (`raw` is `None`) is a place expression (`self.field`), so it can
+        // be borrowed with `.as_ref()` directly. A projected `expr` like `to_le_bytes()`
+        // instead produces a fresh owned value, which must be bound first — borrowing it
+        // inline would drop the value at the end of the statement before the comparison
+        // on the next line gets to use the borrow.
+        FieldMode::Bytes(raw) => {
+            let (lhs_bind, rhs_bind) = match raw {
+                Some(raw) => {
+                    let lhs_expr = project_expr(raw, member, lhs_access);
+                    let rhs_expr = project_expr(raw, member, rhs_access);
+                    (
+                        quote! { let lhs_owned = #lhs_expr; let lhs_bytes: &[u8] = lhs_owned.as_ref(); },
+                        quote! { let rhs_owned = #rhs_expr; let rhs_bytes: &[u8] = rhs_owned.as_ref(); },
+                    )
+                },
+                None => (
+                    quote! { let lhs_bytes: &[u8] = (#lhs_access).as_ref(); },
+                    quote! { let rhs_bytes: &[u8] = (#rhs_access).as_ref(); },
+                ),
+            };
+            Some(quote! {
+                {
+                    #lhs_bind
+                    #rhs_bind
+                    lhs_bytes == rhs_bytes
+                }
+            })
+        },
+    }
+}
+
+/// When `#[hash_with(domain_separated)]` is set, prefixes a field's hash statement with
+/// its declaration-order position (as a `u64`) so that field boundaries become part of
+/// the hashed input.
+///
+/// Only the position is written, not the field's length: a field whose own hashing is
+/// not self-delimiting (such as a `#[hash_with(bytes)]` field) can contain bytes that
+/// read as the next field's discriminator, so two different values can still produce
+/// the same hash stream.
+fn with_field_discriminator(index: usize, domain_separated: bool, stmt: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if !domain_separated {
+        return stmt;
+    }
+    let index = index as u64;
+    quote! {
+        state.write_u64(#index);
+        #stmt
+    }
+}
+
+/// Joins field equality comparisons with `&&`, defaulting to `true` when there are none
+/// (e.g. an empty `struct Foo {}`, or every field is `#[hash_without]`).
+///
+/// Each comparison is parenthesized before joining: `Func` and `Bytes` fields produce
+/// block expressions (`{ ... }`), and `{ ... } && { ... }` would otherwise parse as two
+/// separate statements rather than one boolean expression.
+fn and_chain(exprs: Vec<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+    if exprs.is_empty() {
+        return quote! { true };
+    }
+    let exprs = exprs.into_iter().map(|expr| quote! { (#expr) });
+    quote! { #(#exprs)&&* }
+}
+
+/// The container-level settings read from `#[hash_with(...)]` attributes on the
+/// `struct`/`enum` item itself, as opposed to its fields.
+#[derive(Default)]
+struct ContainerAttrs {
+    /// `#[hash_with(bound = "...")]`: `None` if absent, `Some("")` to emit no bound at
+    /// all, or `Some(predicates)` with the raw `where` predicates to use instead of the
+    /// inferred `T: Hash` bounds, mirroring the custom-bound feature from the
+    /// `derivative` crate.
+    bound: Option<String>,
+    /// `#[hash_with(domain_separated)]`: writes a type-name domain tag and a per-field
+    /// position discriminator into the hasher so that field boundaries become part of the
+    /// hashed input (see [`with_field_discriminator`] for what this does not cover),
+    /// following the format-ambiguity protection in the Aptos/Diem crypto hashing module.
+    domain_separated: bool,
+    /// `#[hash_with(eq)]`: also derive `PartialEq`/`Eq` from the same field set used for
+    /// hashing, so `#[hash_without]` and custom projections can't desync the
+    /// `k1 == k2 ⇒ hash(k1) == hash(k2)` contract.
+    eq: bool,
+}
+
+/// Parses the container-level `#[hash_with(...)]` attribute(s) on a `struct` or `enum`.
+fn parse_container_attrs(attrs: &[Attribute]) -> ContainerAttrs {
+
+    let mut result = ContainerAttrs::default();
+
+    for attr in attrs {
+        if !attr.path().is_ident("hash_with") {
+            continue;
+        }
+
+        let Meta::List(MetaList { tokens, .. }) = &attr.meta else { continue };
+        let metas = Punctuated::<Meta, Comma>::parse_terminated
+            .parse2(tokens.clone())
+            .expect("Failed to parse `hash_with` container attribute!");
+
+        for meta in metas {
+            match meta {
+                Meta::NameValue(MetaNameValue { path, value: Expr::Lit(syn::ExprLit { lit: Lit::Str(bound_str), .. }), .. }) if path.is_ident("bound") => {
+                    result.bound = Some(bound_str.value());
+                },
+                Meta::Path(path) if path.is_ident("domain_separated") => {
+                    result.domain_separated = true;
+                },
+                Meta::Path(path) if path.is_ident("eq") => {
+                    result.eq = true;
+                },
+                _ => {},
+            }
+        }
+    }
+
+    result
+}
 
 /// # HashWith
 ///
@@ -275,6 +711,120 @@ use syn::{parse_macro_input, parse_str, Data, DeriveInput, Expr, Fields, Lit, Me
 /// assert_ne!(get_hash(&v1), get_hash(&v2));
 ///
 /// ```
+///
+/// ## Generic Structs and Custom Bounds Example
+///
+/// [`HashWith`] forwards a struct's generics into the generated `impl`, so generic
+/// structs work out of the box. By default every type parameter gets a `T: Hash` bound,
+/// just like the standard `#[derive(Hash)]`. When that default is wrong — for example
+/// because only some fields actually need `T: Hash` — use the container-level
+/// `#[hash_with(bound = "...")]` attribute to supply the `where` predicates yourself, or
+/// `#[hash_with(bound = "")]` to emit no bound at all.
+///
+/// ```rust
+/// # use hash_with::HashWith;
+/// #
+/// /// Only `name` is hashed, so `T` itself doesn't need `Hash`.
+/// #[derive(HashWith)]
+/// #[hash_with(bound = "")]
+/// struct Wrapper<T> {
+///     name: String,
+///     #[hash_without]
+///     inner: T,
+/// }
+/// ```
+///
+/// ## Enum and Tuple Struct Example
+///
+/// [`HashWith`] also supports tuple structs and enums. Every `#[hash_with(...)]`,
+/// `#[hash_with = "fn"]` and `#[hash_without]` attribute works on enum variant fields
+/// exactly as it does on named struct fields, using `self.0`, `self.1`, etc. to refer to
+/// tuple fields. Each variant's discriminant is hashed first, so two variants that hold
+/// structurally identical payloads never collide.
+///
+/// ```rust
+/// # use hash_with::HashWith;
+/// #
+/// #[derive(HashWith)]
+/// enum Setting {
+///     Flag(bool),
+///     Brightness(#[hash_with(self.0.to_bits())] f64),
+///     Named { label: String },
+/// }
+/// ```
+///
+/// ## Domain-Separated Hashing Example
+///
+/// Plain concatenation-style hashing can produce collisions between structurally
+/// different values when several variable-length fields are written without a delimiter,
+/// as `#[hash_with(bytes)]` fields are: e.g. `{a: b"foo", b: b"bar"}` and
+/// `{a: b"foob", b: b"ar"}` feed the same bytes to the hasher. (`String` fields hashed
+/// through `Hash::hash` do not collide this way, since `str`'s `Hash` impl already ends
+/// each string with a terminator byte.) The container-level
+/// `#[hash_with(domain_separated)]` attribute writes a type-name domain tag once and a
+/// position discriminator before every field, so field boundaries become part of the
+/// hashed input, following the format-ambiguity protection used by the Aptos/Diem crypto
+/// hashing module. Only the position is written, not each field's length. This is opt-in,
+/// so existing users' hashes don't change.
+///
+/// ```rust
+/// # use hash_with::HashWith;
+/// #
+/// #[derive(HashWith)]
+/// #[hash_with(domain_separated)]
+/// struct Pair {
+///     #[hash_with(bytes)]
+///     a: Vec<u8>,
+///     #[hash_with(bytes)]
+///     b: Vec<u8>,
+/// }
+/// ```
+///
+/// ## Deriving a Matching `PartialEq`/`Eq` Example
+///
+/// `#[hash_with]`'s `#[hash_without]` attribute silently breaks the
+/// `k1 == k2 ⇒ hash(k1) == hash(k2)` contract if you also `#[derive(PartialEq)]`,
+/// since the standard derive would compare the ignored field while [`HashWith`] ignores
+/// it. The container-level `#[hash_with(eq)]` attribute derives `PartialEq`/`Eq` from the
+/// same field set the hash uses instead: fields marked `#[hash_without]` are skipped,
+/// and fields with a `#[hash_with(expr)]`/`#[hash_with = "fn"]` projection are compared
+/// through that same projection rather than the raw field.
+///
+/// ```rust
+/// # use hash_with::HashWith;
+/// #
+/// #[derive(HashWith, Debug)]
+/// #[hash_with(eq)]
+/// struct CacheKey {
+///     id: u32,
+///     #[hash_without]
+///     last_accessed: f64,
+/// }
+///
+/// let a = CacheKey { id: 1, last_accessed: 0.0 };
+/// let b = CacheKey { id: 1, last_accessed: 99.9 };
+/// assert_eq!(a, b);
+/// ```
+///
+/// ## Hashing Raw Bytes Example
+///
+/// The `#[hash_with(bytes = "expr")]` attribute (and the bare `#[hash_with(bytes)]` form
+/// for fields that are themselves `AsRef<[u8]>`) pushes bytes straight into the hasher
+/// via [`Hasher::write`](std::hash::Hasher::write) instead of going through [`Hash::hash`].
+/// This is useful for digests, serialized buffers, or `to_bits()` output, where the
+/// per-element dispatch of hashing a slice isn't needed.
+///
+/// ```rust
+/// # use hash_with::HashWith;
+/// #
+/// #[derive(HashWith)]
+/// struct Digest {
+///     #[hash_with(bytes)]
+///     checksum: Vec<u8>,
+///     #[hash_with(bytes = "self.value.to_le_bytes()")]
+///     value: u32,
+/// }
+/// ```
 
 #[proc_macro_derive(HashWith, attributes(hash_with, hash_without))]
 pub fn derive_hash_with(input: TokenStream) -> TokenStream {
@@ -282,86 +832,229 @@ pub fn derive_hash_with(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
 
+    let container_attrs = parse_container_attrs(&input.attrs);
+    let domain_separated = container_attrs.domain_separated;
+    let derive_eq = container_attrs.eq;
+
     let mut hash_stmts = Vec::new();
+    // Only built when `#[hash_with(eq)]` is set; `None` while `derive_eq` is `false`.
+    let mut eq_body = None;
 
-    // Gets the data
-    if let Data::Struct(data_struct) = &input.data {
-        // Gets the named fields
-        if let Fields::Named(fields) = &data_struct.fields {
-
-            // Goes through all the fields
-            for field in &fields.named {
-
-                // Gets the name of each field
-                let field_name = field.ident.as_ref().unwrap();
-                let mut custom_hash_fn = None;
-
-                for attr in &field.attrs {
-                    if attr.path().is_ident("hash_with") {
-
-                        let function_name = match &attr.meta {
-                            Meta::NameValue(
-                                MetaNameValue {
-                                    value: Expr::Lit(syn::ExprLit {
-                                        lit: Lit::Str(
-                                             function_name_str_with_quotes
-                                        ),
-                                        ..
-                                    }),
-                                .. }
-                            ) => {
-                                let func = function_name_str_with_quotes.parse_with(syn::Path::parse_mod_style).expect("Failed to parse string!");
-                                // let func = parse_str::<Expr>(&function_name_str_with_quotes.value()).unwrap_or_else(|_| panic!("Failed to parse string!"));
-                                quote! {
-                                    #func(&self.#field_name, state);
-                                }
-                            },
-                            // Handles the list implementation (i.e. `#[hash_with( ... )]`)
-                            Meta::List(
-                                MetaList {
-                                    tokens,
-                                .. }
-                            ) => {
-                                let expr = parse_str::<Expr>(&tokens.to_string()).expect("Failed to parse tokens").to_token_stream();
-                                quote! {
-                                    #expr.hash(state);
-                                }
-                            },
-                            _ => panic!("Failed to parse `{}` for `hash_with` macro.", attr.to_token_stream().to_string()),
-                        };
-
-                        custom_hash_fn = Some(function_name);
+    // Writes a fixed domain tag derived from the type's name before anything else, so
+    // that two structurally-identical-looking byte streams produced by unrelated types
+    // can never be confused with one another.
+    if domain_separated {
+        hash_stmts.push(quote! {
+            state.write(stringify!(#name).as_bytes());
+        });
+    }
 
+    // Gets the data
+    match &input.data {
+        Data::Struct(data_struct) => {
+            match &data_struct.fields {
+                // Gets the named fields
+                Fields::Named(fields) => {
+                    let mut comparisons = Vec::new();
+                    for (index, field) in fields.named.iter().enumerate() {
+                        let field_name = field.ident.as_ref().unwrap();
+                        let member = Member::Named(field_name.clone());
+                        let mode = parse_field_mode(&field.attrs);
+                        let access = quote! { self.#field_name };
+                        let stmt = hash_stmt_for_field(&mode, &member, &access, false);
+                        hash_stmts.push(with_field_discriminator(index, domain_separated, stmt));
+                        if derive_eq {
+                            let other_access = quote! { other.#field_name };
+                            comparisons.extend(eq_expr_for_field(&mode, &member, &access, &other_access, false));
+                        }
                     }
-
-                    if attr.path().is_ident("hash_without") {
-                        custom_hash_fn = Some(proc_macro2::TokenStream::new());
+                    if derive_eq {
+                        eq_body = Some(and_chain(comparisons));
+                    }
+                },
+                // Gets the tuple fields (i.e. `struct Foo(u64, f64);`)
+                Fields::Unnamed(fields) => {
+                    let mut comparisons = Vec::new();
+                    for (index, field) in fields.unnamed.iter().enumerate() {
+                        let member = Member::Unnamed(Index::from(index));
+                        let mode = parse_field_mode(&field.attrs);
+                        let access = quote! { self.#member };
+                        let stmt = hash_stmt_for_field(&mode, &member, &access, false);
+                        hash_stmts.push(with_field_discriminator(index, domain_separated, stmt));
+                        if derive_eq {
+                            let other_access = quote! { other.#member };
+                            comparisons.extend(eq_expr_for_field(&mode, &member, &access, &other_access, false));
+                        }
+                    }
+                    if derive_eq {
+                        eq_body = Some(and_chain(comparisons));
+                    }
+                },
+                // Unit structs have no fields to hash or compare.
+                Fields::Unit => {
+                    if derive_eq {
+                        eq_body = Some(quote! { true });
                     }
+                },
+            }
+        },
+        Data::Enum(data_enum) => {
+
+            let mut arms = Vec::new();
+            let mut eq_arms = Vec::new();
+
+            for variant in &data_enum.variants {
+                let variant_name = &variant.ident;
 
+                match &variant.fields {
+                    Fields::Named(fields) => {
+                        let field_names: Vec<&Ident> = fields.named.iter().map(|field| field.ident.as_ref().unwrap()).collect();
+                        let other_names: Vec<Ident> = field_names.iter().map(|field_name| format_ident!("other_{}", field_name)).collect();
+                        let mut field_stmts = Vec::new();
+                        let mut comparisons = Vec::new();
+                        for (index, field) in fields.named.iter().enumerate() {
+                            let field_name = field.ident.as_ref().unwrap();
+                            let member = Member::Named(field_name.clone());
+                            let mode = parse_field_mode(&field.attrs);
+                            let access = quote! { #field_name };
+                            let stmt = hash_stmt_for_field(&mode, &member, &access, true);
+                            field_stmts.push(with_field_discriminator(index, domain_separated, stmt));
+                            if derive_eq {
+                                comparisons.extend(eq_expr_for_field(&mode, &member, &access, &other_names[index].to_token_stream(), true));
+                            }
+                        }
+                        arms.push(quote! {
+                            #name::#variant_name { #(#field_names),* } => {
+                                #(#field_stmts)*
+                            }
+                        });
+                        if derive_eq {
+                            let body = and_chain(comparisons);
+                            eq_arms.push(quote! {
+                                (#name::#variant_name { #(#field_names),* }, #name::#variant_name { #(#field_names: #other_names),* }) => #body,
+                            });
+                        }
+                    },
+                    Fields::Unnamed(fields) => {
+                        let bindings: Vec<Ident> = (0..fields.unnamed.len()).map(|index| format_ident!("field_{}", index)).collect();
+                        let other_bindings: Vec<Ident> = (0..fields.unnamed.len()).map(|index| format_ident!("other_field_{}", index)).collect();
+                        let mut field_stmts = Vec::new();
+                        let mut comparisons = Vec::new();
+                        for (index, field) in fields.unnamed.iter().enumerate() {
+                            let member = Member::Unnamed(Index::from(index));
+                            let mode = parse_field_mode(&field.attrs);
+                            let binding = &bindings[index];
+                            let access = quote! { #binding };
+                            let stmt = hash_stmt_for_field(&mode, &member, &access, true);
+                            field_stmts.push(with_field_discriminator(index, domain_separated, stmt));
+                            if derive_eq {
+                                comparisons.extend(eq_expr_for_field(&mode, &member, &access, &other_bindings[index].to_token_stream(), true));
+                            }
+                        }
+                        arms.push(quote! {
+                            #name::#variant_name(#(#bindings),*) => {
+                                #(#field_stmts)*
+                            }
+                        });
+                        if derive_eq {
+                            let body = and_chain(comparisons);
+                            eq_arms.push(quote! {
+                                (#name::#variant_name(#(#bindings),*), #name::#variant_name(#(#other_bindings),*)) => #body,
+                            });
+                        }
+                    },
+                    Fields::Unit => {
+                        arms.push(quote! {
+                            #name::#variant_name => {}
+                        });
+                        if derive_eq {
+                            eq_arms.push(quote! {
+                                (#name::#variant_name, #name::#variant_name) => true,
+                            });
+                        }
+                    },
                 }
 
-                let hash_function = match custom_hash_fn {
-                    Some(tokens) => tokens,
-                    None => quote! { self.#field_name.hash(state); }
-                };
+            }
 
-                hash_stmts.push(hash_function);
+            // Writes the variant's discriminant before its fields so that two different
+            // variants carrying structurally identical payloads never hash the same,
+            // matching how the standard `#[derive(Hash)]` handles enums.
+            hash_stmts.push(quote! {
+                std::mem::discriminant(self).hash(state);
+            });
+            hash_stmts.push(quote! {
+                match self {
+                    #(#arms)*
+                }
+            });
 
+            if derive_eq {
+                // Different variants are never equal, regardless of payload.
+                eq_body = Some(quote! {
+                    match (self, other) {
+                        #(#eq_arms)*
+                        _ => false,
+                    }
+                });
             }
 
-        }
-        else {
-            panic!("HashWith only supports structs with named fields!");
-        }
+        },
+        Data::Union(_) => panic!("HashWith does not support unions!"),
+    }
 
+    // Forwards the struct's generics into the generated `impl`, adding a bound for
+    // every type parameter by default (mirroring `#[derive(Hash)]`) unless the user
+    // overrides it with a container-level `#[hash_with(bound = "...")]` attribute.
+    // When `#[hash_with(eq)]` is also set, the same `where_clause` backs the generated
+    // `PartialEq` impl, so every type parameter additionally needs `PartialEq` — without
+    // it, a plain (non-`hash_without`, non-projected) field of generic type fails to
+    // compile with `==` pointing at the derive macro rather than user code.
+    let mut generics = input.generics.clone();
+    match container_attrs.bound {
+        Some(bound_str) if !bound_str.is_empty() => {
+            let predicates = Punctuated::<WherePredicate, Comma>::parse_terminated
+                .parse_str(&bound_str)
+                .expect("Failed to parse `bound` predicates!");
+            generics.make_where_clause().predicates.extend(predicates);
+        },
+        // `#[hash_with(bound = "")]` opts out of adding any bound at all.
+        Some(_) => {},
+        None => {
+            for param in &input.generics.params {
+                if let GenericParam::Type(type_param) = param {
+                    let ident = &type_param.ident;
+                    generics.make_where_clause().predicates.push(parse_quote! { #ident: std::hash::Hash });
+                    if derive_eq {
+                        generics.make_where_clause().predicates.push(parse_quote! { #ident: std::cmp::PartialEq });
+                    }
+                }
+            }
+        },
     }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    // `#[hash_with(eq)]` derives `PartialEq`/`Eq` from the same field set as the hash,
+    // so the two can never fall out of sync the way a plain `#[derive(PartialEq)]`
+    // would if it compared a field that `#[hash_without]` excludes from hashing.
+    let eq_impl = eq_body.map(|body| quote! {
+        impl #impl_generics PartialEq for #name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                #body
+            }
+        }
+
+        impl #impl_generics Eq for #name #ty_generics #where_clause {}
+    });
 
     let expanded = quote! {
-        impl std::hash::Hash for #name {
+        impl #impl_generics std::hash::Hash for #name #ty_generics #where_clause {
             fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
                 #(#hash_stmts)*
             }
         }
+
+        #eq_impl
     };
 
     return TokenStream::from(expanded);
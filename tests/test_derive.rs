@@ -67,4 +67,266 @@ mod tests {
         assert_ne!(foo_2.to_hash(), foo_3.to_hash());
     }
 
+    #[derive(Default, HashWith)]
+    struct Generic<T> {
+        a: u64,
+        #[hash_with(self.b)]
+        b: T,
+    }
+
+    impl<T: Hash> Generic<T> {
+        pub fn to_hash(&self) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            self.hash(&mut hasher);
+            return hasher.finish();
+        }
+    }
+
+    #[derive(Default, HashWith)]
+    #[hash_with(bound = "")]
+    struct GenericNoBound<T> {
+        a: u64,
+        #[hash_without]
+        b: T,
+    }
+
+    impl<T> GenericNoBound<T> {
+        pub fn to_hash(&self) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            self.hash(&mut hasher);
+            return hasher.finish();
+        }
+    }
+
+    #[test]
+    /// Test to ensure generic structs compile and hash their type parameter.
+    fn checking_generic_struct() {
+        let g1 = Generic { a: 1, b: 2u64 };
+        let g2 = Generic { a: 1, b: 3u64 };
+        assert_ne!(g1.to_hash(), g2.to_hash());
+    }
+
+    #[test]
+    /// Test to ensure `#[hash_with(bound = "")]` compiles without requiring `T: Hash`.
+    fn checking_generic_struct_no_bound() {
+        struct NotHashable;
+        let g1 = GenericNoBound { a: 1, b: NotHashable };
+        let g2 = GenericNoBound { a: 2, b: NotHashable };
+        assert_ne!(g1.to_hash(), g2.to_hash());
+    }
+
+    #[derive(HashWith)]
+    struct TupleStruct(u64, #[hash_with(self.1.to_bits())] f64);
+
+    impl TupleStruct {
+        pub fn to_hash(&self) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            self.hash(&mut hasher);
+            return hasher.finish();
+        }
+    }
+
+    #[test]
+    /// Test to ensure tuple structs hash their fields, including `self.N` custom exprs.
+    fn checking_tuple_struct() {
+        let t1 = TupleStruct(1, 1.0);
+        let t2 = TupleStruct(1, 2.0);
+        assert_ne!(t1.to_hash(), t2.to_hash());
+    }
+
+    #[derive(HashWith)]
+    enum Setting {
+        Flag(bool),
+        Brightness(#[hash_with(self.0.to_bits())] f64),
+        Named { label: String },
+        Level(u64),
+    }
+
+    impl Setting {
+        pub fn to_hash(&self) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            self.hash(&mut hasher);
+            return hasher.finish();
+        }
+    }
+
+    #[test]
+    /// Test to ensure enum variants with differing payloads hash differently.
+    fn checking_enum_variant_fields() {
+        let flag_true = Setting::Flag(true);
+        let flag_false = Setting::Flag(false);
+        assert_ne!(flag_true.to_hash(), flag_false.to_hash());
+
+        let bright_1 = Setting::Brightness(1.0);
+        let bright_2 = Setting::Brightness(2.0);
+        assert_ne!(bright_1.to_hash(), bright_2.to_hash());
+
+        let named_1 = Setting::Named { label: "a".to_string() };
+        let named_2 = Setting::Named { label: "b".to_string() };
+        assert_ne!(named_1.to_hash(), named_2.to_hash());
+    }
+
+    #[test]
+    /// Test to ensure distinct variants don't collide even with matching discriminant-free payloads.
+    fn checking_enum_variant_discriminant() {
+        let flag_false = Setting::Flag(false);
+        let bright_0 = Setting::Brightness(0.0);
+        assert_ne!(flag_false.to_hash(), bright_0.to_hash());
+
+        // Both payloads hash as the same `0u64`, so only the discriminant tells them apart.
+        let level_0 = Setting::Level(0);
+        assert_ne!(bright_0.to_hash(), level_0.to_hash());
+    }
+
+    #[derive(HashWith)]
+    #[hash_with(domain_separated)]
+    struct BytePair {
+        #[hash_with(bytes)]
+        a: Vec<u8>,
+        #[hash_with(bytes)]
+        b: Vec<u8>,
+    }
+
+    impl BytePair {
+        pub fn to_hash(&self) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            self.hash(&mut hasher);
+            return hasher.finish();
+        }
+    }
+
+    #[derive(HashWith)]
+    struct BytePairNoDomainSeparation {
+        #[hash_with(bytes)]
+        a: Vec<u8>,
+        #[hash_with(bytes)]
+        b: Vec<u8>,
+    }
+
+    impl BytePairNoDomainSeparation {
+        pub fn to_hash(&self) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            self.hash(&mut hasher);
+            return hasher.finish();
+        }
+    }
+
+    #[test]
+    /// Test to ensure `#[hash_with(domain_separated)]` distinguishes values that shift
+    /// bytes across a field boundary when fields bypass `Hash::hash` via
+    /// `#[hash_with(bytes)]`. `std`'s `Hash` impl for `String`/`str` already ends each
+    /// string with a terminator byte, so this collision (and the attribute's fix for it) only shows up
+    /// for fields written straight into the hasher via `Hasher::write`.
+    fn checking_domain_separated() {
+        // Without `#[hash_with(domain_separated)]`, shifting bytes across the field
+        // boundary produces the exact same concatenated byte stream, so the hashes collide.
+        let no_sep_1 = BytePairNoDomainSeparation { a: b"foo".to_vec(), b: b"bar".to_vec() };
+        let no_sep_2 = BytePairNoDomainSeparation { a: b"foob".to_vec(), b: b"ar".to_vec() };
+        assert_eq!(no_sep_1.to_hash(), no_sep_2.to_hash());
+
+        // With it, the per-field position discriminator breaks the collision.
+        let p1 = BytePair { a: b"foo".to_vec(), b: b"bar".to_vec() };
+        let p2 = BytePair { a: b"foob".to_vec(), b: b"ar".to_vec() };
+        assert_ne!(p1.to_hash(), p2.to_hash());
+    }
+
+    #[derive(HashWith, Debug)]
+    #[hash_with(eq)]
+    struct CacheKey {
+        id: u32,
+        #[hash_without]
+        last_accessed: f64,
+    }
+
+    #[test]
+    /// Test to ensure `#[hash_with(eq)]` ignores `#[hash_without]` fields in equality,
+    /// matching what they already mean for hashing.
+    fn checking_eq_respects_hash_without() {
+        let a = CacheKey { id: 1, last_accessed: 0.0 };
+        let b = CacheKey { id: 1, last_accessed: 99.9 };
+        let c = CacheKey { id: 2, last_accessed: 0.0 };
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[derive(HashWith, Debug)]
+    #[hash_with(eq)]
+    struct Brightness2 {
+        #[hash_with(self.inner.to_bits())]
+        inner: f64,
+    }
+
+    #[test]
+    /// Test to ensure `#[hash_with(eq)]` compares `#[hash_with(expr)]` fields via the
+    /// same projection used for hashing, rather than the raw (non-`Eq`) field.
+    fn checking_eq_uses_inline_projection() {
+        let a = Brightness2 { inner: 1.0 };
+        let b = Brightness2 { inner: 1.0 };
+        let c = Brightness2 { inner: 2.0 };
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[derive(HashWith, Debug)]
+    #[hash_with(eq)]
+    struct GenEq<T> {
+        a: T,
+    }
+
+    #[test]
+    /// Test to ensure `#[hash_with(eq)]` adds the `PartialEq` bound its generated
+    /// `PartialEq` impl needs for a plain generic field, alongside the default `Hash` bound.
+    fn checking_eq_with_generic_field() {
+        let a = GenEq { a: 1u64 };
+        let b = GenEq { a: 1u64 };
+        let c = GenEq { a: 2u64 };
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[derive(HashWith, Debug)]
+    #[hash_with(eq)]
+    enum Setting2 {
+        Flag(bool),
+        Named { label: String },
+    }
+
+    #[test]
+    /// Test to ensure `#[hash_with(eq)]` works on enums, including across variants.
+    fn checking_eq_on_enum() {
+        assert_eq!(Setting2::Flag(true), Setting2::Flag(true));
+        assert_ne!(Setting2::Flag(true), Setting2::Flag(false));
+        assert_ne!(Setting2::Flag(true), Setting2::Named { label: "x".to_string() });
+    }
+
+    #[derive(HashWith, Debug)]
+    #[hash_with(eq)]
+    struct Digest {
+        #[hash_with(bytes)]
+        checksum: Vec<u8>,
+        #[hash_with(bytes = "self.value.to_le_bytes()")]
+        value: u32,
+    }
+
+    impl Digest {
+        pub fn to_hash(&self) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            self.hash(&mut hasher);
+            return hasher.finish();
+        }
+    }
+
+    #[test]
+    /// Test to ensure `#[hash_with(bytes)]` and `#[hash_with(bytes = "expr")]` write raw
+    /// bytes into the hasher and compare equal via the same bytes under `#[hash_with(eq)]`.
+    fn checking_hash_with_bytes() {
+        let d1 = Digest { checksum: vec![1, 2, 3], value: 42 };
+        let d2 = Digest { checksum: vec![1, 2, 3], value: 42 };
+        let d3 = Digest { checksum: vec![1, 2, 4], value: 42 };
+        assert_eq!(d1, d2);
+        assert_eq!(d1.to_hash(), d2.to_hash());
+        assert_ne!(d1, d3);
+        assert_ne!(d1.to_hash(), d3.to_hash());
+    }
+
 }